@@ -1,19 +1,126 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
 use cpal::{FromSample, Sample as CpalSample};
-use std::marker::PhantomData;
+use core::marker::PhantomData;
+
+// Float rounding lives on the `std` float types, not in `core`. This module otherwise only uses
+// `core` + `alloc`, so the single thing standing between it and a `no_std` build is the rounding
+// backend: enable the optional `libm` feature (the same approach num-traits uses to revive float
+// math without `std`) to route rounding through `libm` instead. Without `std` and without `libm`
+// the rounding helpers below will not compile — a `no_std` build must turn `libm` on. The public
+// trait signatures are unaffected; only the math backend switches.
+#[cfg(not(feature = "libm"))]
+#[inline]
+fn round_f32(x: f32) -> f32 {
+    x.round()
+}
+
+#[cfg(feature = "libm")]
+#[inline]
+fn round_f32(x: f32) -> f32 {
+    libm::roundf(x)
+}
+
+#[cfg(not(feature = "libm"))]
+#[inline]
+fn round_f64(x: f64) -> f64 {
+    x.round()
+}
+
+#[cfg(feature = "libm")]
+#[inline]
+fn round_f64(x: f64) -> f64 {
+    libm::round(x)
+}
+
+/// A tiny xorshift64 PRNG, used to generate TPDF dither without pulling in an RNG crate.
+#[derive(Clone, Debug)]
+struct Xorshift64 {
+    state: u64,
+}
+
+impl Xorshift64 {
+    #[inline]
+    fn new(seed: u64) -> Xorshift64 {
+        // xorshift must never be seeded with zero
+        Xorshift64 {
+            state: if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed },
+        }
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// Returns a uniform `f32` in the `[0.0, 1.0)` range.
+    #[inline]
+    fn next_f32(&mut self) -> f32 {
+        // keep the top 24 bits, the mantissa width of an f32
+        (self.next_u64() >> 40) as f32 / (1u32 << 24) as f32
+    }
+}
 
 /// Converts the samples data type to `O`.
 #[derive(Clone, Debug)]
 pub struct DataConverter<I, O> {
     input: I,
+    integer_domain: bool,
+    dither: Option<Xorshift64>,
     marker: PhantomData<O>,
 }
 
 impl<I, O> DataConverter<I, O> {
     /// Builds a new converter.
+    ///
+    /// Conversions go through `f32`, which is lossless for float targets but quantizes integer
+    /// material. Use [`new_integer`](DataConverter::new_integer) when both endpoints are integer
+    /// types to keep full precision.
     #[inline]
     pub fn new(input: I) -> DataConverter<I, O> {
         DataConverter {
             input,
+            integer_domain: false,
+            dither: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Builds a new converter that applies TPDF dither before bit-depth reduction.
+    ///
+    /// When the target type (`i16`, `u16`, `i8`, ...) resolves fewer bits than the source
+    /// (`f32`, `i32`, `I24`, ...), plain truncation produces correlated quantization distortion.
+    /// This constructor adds triangular-PDF dither — the sum of two independent uniform draws, each
+    /// one LSB of the target step wide — before rounding, decorrelating the error into a flat noise
+    /// floor. Dither is a no-op for float targets and for upward conversions.
+    #[inline]
+    pub fn new_with_dither(input: I) -> DataConverter<I, O> {
+        DataConverter {
+            input,
+            integer_domain: false,
+            dither: Some(Xorshift64::new(0x2545_F491_4F6C_DD1D)),
+            marker: PhantomData,
+        }
+    }
+
+    /// Builds a new converter that stays in the integer domain.
+    ///
+    /// This uses the integer-domain paths of [`Sample::from_sample`] instead of routing through
+    /// `f32`. The paths carry 16-bit precision, so it is lossless for the `i16` and `u16` targets
+    /// this mode is intended for (e.g. `i16 -> i16` or `u16 -> i16`). Wider targets (`i32`, `I24`)
+    /// still receive only 16 significant bits, so prefer [`new`](DataConverter::new) for those.
+    #[inline]
+    pub fn new_integer(input: I) -> DataConverter<I, O> {
+        DataConverter {
+            input,
+            integer_domain: true,
+            dither: None,
             marker: PhantomData,
         }
     }
@@ -41,7 +148,27 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<O> {
-        self.input.next().map(|s| CpalSample::from_sample(s))
+        let s = self.input.next()?;
+
+        if let Some(rng) = self.dither.as_mut() {
+            // Only dither when quantizing down to a coarser integer grid.
+            let engage = match (<I::Item as Sample>::quantization_step(), O::quantization_step()) {
+                (_, None) => false,
+                (None, Some(_)) => true,
+                (Some(src), Some(tgt)) => src < tgt,
+            };
+            if engage {
+                let step = O::quantization_step().unwrap();
+                let dither = (rng.next_f32() + rng.next_f32() - 1.0) * step;
+                return Some(O::from_f32_quantized(s.to_f32() + dither));
+            }
+        }
+
+        Some(if self.integer_domain {
+            Sample::from_sample(s)
+        } else {
+            CpalSample::from_sample(s)
+        })
     }
 
     #[inline]
@@ -58,20 +185,243 @@ where
 {
 }
 
+/// A type-erased [`DataConverter`] that targets a [`SampleFormat`] chosen at runtime.
+///
+/// It wraps a source iterator of samples and yields the converted samples as interleaved
+/// little-endian bytes in the requested format. This lets downstream code build an output pipeline
+/// from a runtime-selected device format without hand-writing the dispatch over every concrete
+/// type.
+pub struct AnyDataConverter<'a> {
+    inner: Box<dyn Iterator<Item = u8> + 'a>,
+}
+
+impl<'a> AnyDataConverter<'a> {
+    /// Builds a converter that yields `input` re-encoded into `target`.
+    pub fn new<I>(input: I, target: SampleFormat) -> AnyDataConverter<'a>
+    where
+        I: Iterator + 'a,
+        I::Item: Sample,
+        i8: FromSample<I::Item>,
+        u8: FromSample<I::Item>,
+        i16: FromSample<I::Item>,
+        u16: FromSample<I::Item>,
+        I24: FromSample<I::Item>,
+        i32: FromSample<I::Item>,
+        f32: FromSample<I::Item>,
+        f64: FromSample<I::Item>,
+    {
+        let inner: Box<dyn Iterator<Item = u8> + 'a> = match target {
+            SampleFormat::I8 => {
+                Box::new(DataConverter::<_, i8>::new(input).flat_map(|s| s.to_le_bytes()))
+            }
+            SampleFormat::U8 => {
+                Box::new(DataConverter::<_, u8>::new(input).flat_map(|s| s.to_le_bytes()))
+            }
+            SampleFormat::I16 => {
+                Box::new(DataConverter::<_, i16>::new(input).flat_map(|s| s.to_le_bytes()))
+            }
+            SampleFormat::U16 => {
+                Box::new(DataConverter::<_, u16>::new(input).flat_map(|s| s.to_le_bytes()))
+            }
+            SampleFormat::I24 => {
+                Box::new(DataConverter::<_, I24>::new(input).flat_map(|s| s.to_le_bytes()))
+            }
+            SampleFormat::I32 => {
+                Box::new(DataConverter::<_, i32>::new(input).flat_map(|s| s.to_le_bytes()))
+            }
+            SampleFormat::F32 => {
+                Box::new(DataConverter::<_, f32>::new(input).flat_map(|s| s.to_le_bytes()))
+            }
+            SampleFormat::F64 => {
+                Box::new(DataConverter::<_, f64>::new(input).flat_map(|s| s.to_le_bytes()))
+            }
+        };
+
+        AnyDataConverter { inner }
+    }
+}
+
+impl Iterator for AnyDataConverter<'_> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// A packed 24-bit signed sample.
+///
+/// The value is stored sign-extended in an `i32` and is always kept within the 24-bit range
+/// `[-8_388_608, 8_388_607]`. Silence corresponds to `0` and the minimum and maximum amplitudes are
+/// `-(2^23)` and `2^23 - 1` respectively. This mirrors the `I24` format that CPAL now exposes in its
+/// `SampleFormat` enum, letting us carry 24-bit material without first widening it to `i32` or `f32`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+pub struct I24(i32);
+
+impl I24 {
+    /// The smallest representable value, `-(2^23)`.
+    pub const MIN: i32 = -8_388_608;
+    /// The largest representable value, `2^23 - 1`.
+    pub const MAX: i32 = 8_388_607;
+
+    /// Builds a new `I24`, clamping `value` into the 24-bit range.
+    #[inline]
+    pub fn new(value: i32) -> I24 {
+        I24(value.clamp(Self::MIN, Self::MAX))
+    }
+
+    /// Returns the sign-extended `i32` representation.
+    #[inline]
+    pub fn inner(self) -> i32 {
+        self.0
+    }
+
+    /// Returns the 24-bit value as three little-endian bytes.
+    #[inline]
+    pub fn to_le_bytes(self) -> [u8; 3] {
+        let v = self.0 as u32;
+        [v as u8, (v >> 8) as u8, (v >> 16) as u8]
+    }
+}
+
+impl CpalSample for I24 {
+    type Signed = i32;
+    type Float = f32;
+    const EQUILIBRIUM: I24 = I24(0);
+}
+
+impl FromSample<I24> for I24 {
+    #[inline]
+    fn from_sample_(s: I24) -> I24 {
+        s
+    }
+}
+
+impl FromSample<i8> for I24 {
+    #[inline]
+    fn from_sample_(s: i8) -> I24 {
+        I24::new((s as i32) << 16)
+    }
+}
+
+impl FromSample<u8> for I24 {
+    #[inline]
+    fn from_sample_(s: u8) -> I24 {
+        I24::new((s as i32 - 128) << 16)
+    }
+}
+
+impl FromSample<i16> for I24 {
+    #[inline]
+    fn from_sample_(s: i16) -> I24 {
+        I24::new((s as i32) << 8)
+    }
+}
+
+impl FromSample<u16> for I24 {
+    #[inline]
+    fn from_sample_(s: u16) -> I24 {
+        I24::new((s as i32 - 32768) << 8)
+    }
+}
+
+impl FromSample<i32> for I24 {
+    #[inline]
+    fn from_sample_(s: i32) -> I24 {
+        I24::new(s >> 8)
+    }
+}
+
+impl FromSample<f64> for I24 {
+    #[inline]
+    fn from_sample_(s: f64) -> I24 {
+        I24::new((s * 8_388_608.0) as i32)
+    }
+}
+
+impl FromSample<I24> for i32 {
+    #[inline]
+    fn from_sample_(s: I24) -> i32 {
+        s.0 << 8
+    }
+}
+
+impl FromSample<f32> for I24 {
+    #[inline]
+    fn from_sample_(s: f32) -> I24 {
+        I24::new((s * 8_388_608.0) as i32)
+    }
+}
+
+impl FromSample<I24> for f32 {
+    #[inline]
+    fn from_sample_(s: I24) -> f32 {
+        s.0 as f32 / 8_388_608.0
+    }
+}
+
+/// Runtime descriptor of a sample's in-memory format.
+///
+/// This mirrors CPAL's `SampleFormat` so that code which only learns its output format at runtime
+/// (e.g. from a device config) can pick a converter without a `match` naming every concrete type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SampleFormat {
+    /// Signed 8-bit.
+    I8,
+    /// Unsigned 8-bit.
+    U8,
+    /// Signed 16-bit.
+    I16,
+    /// Unsigned 16-bit.
+    U16,
+    /// Packed signed 24-bit (see [`I24`]).
+    I24,
+    /// Signed 32-bit.
+    I32,
+    /// 32-bit float.
+    F32,
+    /// 64-bit float.
+    F64,
+}
+
+impl SampleFormat {
+    /// Returns the size in bytes of one sample in this format.
+    #[inline]
+    pub fn sample_size(self) -> usize {
+        match self {
+            SampleFormat::I8 | SampleFormat::U8 => 1,
+            SampleFormat::I16 | SampleFormat::U16 => 2,
+            SampleFormat::I24 => 3,
+            SampleFormat::I32 | SampleFormat::F32 => 4,
+            SampleFormat::F64 => 8,
+        }
+    }
+}
+
 /// Represents a value of a single sample.
 ///
-/// This trait is implemented by default on three types: `i16`, `u16` and `f32`.
+/// This trait is implemented by default on the native integer and float types `i8`, `u8`, `i16`,
+/// `u16`, `i32`, `f32` and `f64`, as well as on the packed 24-bit [`I24`] newtype.
 ///
-/// - For `i16`, silence corresponds to the value `0`. The minimum and maximum amplitudes are
-///   represented by `i16::min_value()` and `i16::max_value()` respectively.
-/// - For `u16`, silence corresponds to the value `u16::max_value() / 2`. The minimum and maximum
-///   amplitudes are represented by `0` and `u16::max_value()` respectively.
-/// - For `f32`, silence corresponds to the value `0.0`. The minimum and maximum amplitudes are
-///   represented by `-1.0` and `1.0` respectively.
+/// - For the signed integer types (`i8`, `i16`, `i32`, `I24`), silence corresponds to the value `0`
+///   and the minimum and maximum amplitudes are the type's `MIN` and `MAX`.
+/// - For the unsigned integer types (`u8`, `u16`), silence corresponds to the value `MAX / 2 + 1`
+///   and the minimum and maximum amplitudes are `0` and `MAX` respectively.
+/// - For the float types (`f32`, `f64`), silence corresponds to the value `0.0` and the minimum and
+///   maximum amplitudes are represented by `-1.0` and `1.0` respectively.
 ///
 /// You can implement this trait on your own type as well if you wish so.
 ///
 pub trait Sample: CpalSample {
+    /// The runtime [`SampleFormat`] descriptor matching this type.
+    const FORMAT: SampleFormat;
+
     /// Linear interpolation between two samples.
     ///
     /// The result should be equvivalent to
@@ -83,6 +433,31 @@ pub trait Sample: CpalSample {
     /// Converts the sample to a f32 value.
     fn to_f32(self) -> f32;
 
+    /// Converts the sample to an `i16` value in the integer domain.
+    ///
+    /// Unlike [`to_f32`](Sample::to_f32) this stays in the integer domain for integer sources, so
+    /// `i16 -> i16` and `u16 -> i16` are lossless instead of quantizing through `f32`.
+    fn to_i16(self) -> i16;
+
+    /// Converts the sample to a `u16` value in the integer domain.
+    fn to_u16(self) -> u16;
+
+    /// Builds a sample of this type from a sample of any other [`Sample`] type.
+    ///
+    /// Integer targets go through the 16-bit integer-domain paths ([`to_i16`](Sample::to_i16) /
+    /// [`to_u16`](Sample::to_u16)), which is lossless for `i16`/`u16` targets but carries only 16
+    /// significant bits into wider targets (`i32`, `I24`); float targets go through
+    /// [`to_f32`](Sample::to_f32).
+    fn from_sample<S: Sample>(s: S) -> Self;
+
+    /// The quantization step of this type expressed in the `[-1.0, 1.0]` working float domain, or
+    /// `None` for float types (which are not quantized). A smaller step means a finer grid.
+    fn quantization_step() -> Option<f32>;
+
+    /// Builds a sample from a working-domain `f32` amplitude (nominally in `[-1.0, 1.0]`), rounding
+    /// to the nearest representable value and saturating. Used by the dithering path.
+    fn from_f32_quantized(value: f32) -> Self;
+
     /// Calls `saturating_add` on the sample.
     fn saturating_add(self, other: Self) -> Self;
 
@@ -91,6 +466,8 @@ pub trait Sample: CpalSample {
 }
 
 impl Sample for u16 {
+    const FORMAT: SampleFormat = SampleFormat::U16;
+
     #[inline]
     fn lerp(first: u16, second: u16, numerator: u32, denominator: u32) -> u16 {
         let d =
@@ -109,6 +486,32 @@ impl Sample for u16 {
         (self as f32 - 32768.0) / 32768.0
     }
 
+    #[inline]
+    fn to_i16(self) -> i16 {
+        // u16 -> i16 subtracts the bias; the `< 32768` half maps to negative values
+        (self as i32 - 32768) as i16
+    }
+
+    #[inline]
+    fn to_u16(self) -> u16 {
+        self
+    }
+
+    #[inline]
+    fn from_sample<S: Sample>(s: S) -> u16 {
+        s.to_u16()
+    }
+
+    #[inline]
+    fn quantization_step() -> Option<f32> {
+        Some(1.0 / 32768.0)
+    }
+
+    #[inline]
+    fn from_f32_quantized(value: f32) -> u16 {
+        round_f32(value * 32768.0 + 32768.0).clamp(0.0, u16::MAX as f32) as u16
+    }
+
     #[inline]
     fn saturating_add(self, other: u16) -> u16 {
         self.saturating_add(other)
@@ -121,6 +524,8 @@ impl Sample for u16 {
 }
 
 impl Sample for i16 {
+    const FORMAT: SampleFormat = SampleFormat::I16;
+
     #[inline]
     fn lerp(first: i16, second: i16, numerator: u32, denominator: u32) -> i16 {
         let d =
@@ -139,6 +544,32 @@ impl Sample for i16 {
         self as f32 / 32768.0
     }
 
+    #[inline]
+    fn to_i16(self) -> i16 {
+        self
+    }
+
+    #[inline]
+    fn to_u16(self) -> u16 {
+        // i16 -> u16 adds the bias
+        (self as i32 + 32768) as u16
+    }
+
+    #[inline]
+    fn from_sample<S: Sample>(s: S) -> i16 {
+        s.to_i16()
+    }
+
+    #[inline]
+    fn quantization_step() -> Option<f32> {
+        Some(1.0 / 32768.0)
+    }
+
+    #[inline]
+    fn from_f32_quantized(value: f32) -> i16 {
+        round_f32(value * 32768.0).clamp(i16::MIN as f32, i16::MAX as f32) as i16
+    }
+
     #[inline]
     fn saturating_add(self, other: i16) -> i16 {
         self.saturating_add(other)
@@ -151,6 +582,8 @@ impl Sample for i16 {
 }
 
 impl Sample for f32 {
+    const FORMAT: SampleFormat = SampleFormat::F32;
+
     #[inline]
     fn lerp(first: f32, second: f32, numerator: u32, denominator: u32) -> f32 {
         first + (second - first) * numerator as f32 / denominator as f32
@@ -167,6 +600,31 @@ impl Sample for f32 {
         self
     }
 
+    #[inline]
+    fn to_i16(self) -> i16 {
+        (self * 32768.0).clamp(i16::MIN as f32, i16::MAX as f32) as i16
+    }
+
+    #[inline]
+    fn to_u16(self) -> u16 {
+        (self * 32768.0 + 32768.0).clamp(u16::MIN as f32, u16::MAX as f32) as u16
+    }
+
+    #[inline]
+    fn from_sample<S: Sample>(s: S) -> f32 {
+        s.to_f32()
+    }
+
+    #[inline]
+    fn quantization_step() -> Option<f32> {
+        None
+    }
+
+    #[inline]
+    fn from_f32_quantized(value: f32) -> f32 {
+        value
+    }
+
     #[inline]
     fn saturating_add(self, other: f32) -> f32 {
         self + other
@@ -178,6 +636,289 @@ impl Sample for f32 {
     }
 }
 
+impl Sample for f64 {
+    const FORMAT: SampleFormat = SampleFormat::F64;
+
+    #[inline]
+    fn lerp(first: f64, second: f64, numerator: u32, denominator: u32) -> f64 {
+        first + (second - first) * numerator as f64 / denominator as f64
+    }
+
+    #[inline]
+    fn amplify(self, value: f32) -> f64 {
+        self * value as f64
+    }
+
+    #[inline]
+    fn to_f32(self) -> f32 {
+        // f64 only needs narrowing to reach the correct format
+        self as f32
+    }
+
+    #[inline]
+    fn to_i16(self) -> i16 {
+        (self * 32768.0).clamp(i16::MIN as f64, i16::MAX as f64) as i16
+    }
+
+    #[inline]
+    fn to_u16(self) -> u16 {
+        (self * 32768.0 + 32768.0).clamp(u16::MIN as f64, u16::MAX as f64) as u16
+    }
+
+    #[inline]
+    fn from_sample<S: Sample>(s: S) -> f64 {
+        s.to_f32() as f64
+    }
+
+    #[inline]
+    fn quantization_step() -> Option<f32> {
+        None
+    }
+
+    #[inline]
+    fn from_f32_quantized(value: f32) -> f64 {
+        value as f64
+    }
+
+    #[inline]
+    fn saturating_add(self, other: f64) -> f64 {
+        self + other
+    }
+
+    #[inline]
+    fn zero_value() -> f64 {
+        0.0
+    }
+}
+
+impl Sample for i8 {
+    const FORMAT: SampleFormat = SampleFormat::I8;
+
+    #[inline]
+    fn lerp(first: i8, second: i8, numerator: u32, denominator: u32) -> i8 {
+        let d =
+            first as i64 + (second as i64 - first as i64) * numerator as i64 / denominator as i64;
+        i8::try_from(d).expect("numerator / denominator is within [0, 1] range")
+    }
+
+    #[inline]
+    fn amplify(self, value: f32) -> i8 {
+        ((self as f32) * value) as i8
+    }
+
+    #[inline]
+    fn to_f32(self) -> f32 {
+        // Convert i8 to f32 in the range [-1.0, 1.0]
+        self as f32 / 128.0
+    }
+
+    #[inline]
+    fn to_i16(self) -> i16 {
+        (self as i16) << 8
+    }
+
+    #[inline]
+    fn to_u16(self) -> u16 {
+        (((self as i32) << 8) + 32768) as u16
+    }
+
+    #[inline]
+    fn from_sample<S: Sample>(s: S) -> i8 {
+        (s.to_i16() >> 8) as i8
+    }
+
+    #[inline]
+    fn quantization_step() -> Option<f32> {
+        Some(1.0 / 128.0)
+    }
+
+    #[inline]
+    fn from_f32_quantized(value: f32) -> i8 {
+        round_f32(value * 128.0).clamp(i8::MIN as f32, i8::MAX as f32) as i8
+    }
+
+    #[inline]
+    fn saturating_add(self, other: i8) -> i8 {
+        self.saturating_add(other)
+    }
+
+    #[inline]
+    fn zero_value() -> i8 {
+        0
+    }
+}
+
+impl Sample for u8 {
+    const FORMAT: SampleFormat = SampleFormat::U8;
+
+    #[inline]
+    fn lerp(first: u8, second: u8, numerator: u32, denominator: u32) -> u8 {
+        let d =
+            first as i64 + (second as i64 - first as i64) * numerator as i64 / denominator as i64;
+        u8::try_from(d).expect("numerator / denominator is within [0, 1] range")
+    }
+
+    #[inline]
+    fn amplify(self, value: f32) -> u8 {
+        ((self as f32) * value) as u8
+    }
+
+    #[inline]
+    fn to_f32(self) -> f32 {
+        // Convert u8 to f32 in the range [-1.0, 1.0]
+        (self as f32 - 128.0) / 128.0
+    }
+
+    #[inline]
+    fn to_i16(self) -> i16 {
+        ((self as i32 - 128) << 8) as i16
+    }
+
+    #[inline]
+    fn to_u16(self) -> u16 {
+        (self as u16) << 8
+    }
+
+    #[inline]
+    fn from_sample<S: Sample>(s: S) -> u8 {
+        (s.to_u16() >> 8) as u8
+    }
+
+    #[inline]
+    fn quantization_step() -> Option<f32> {
+        Some(1.0 / 128.0)
+    }
+
+    #[inline]
+    fn from_f32_quantized(value: f32) -> u8 {
+        round_f32(value * 128.0 + 128.0).clamp(0.0, u8::MAX as f32) as u8
+    }
+
+    #[inline]
+    fn saturating_add(self, other: u8) -> u8 {
+        self.saturating_add(other)
+    }
+
+    #[inline]
+    fn zero_value() -> u8 {
+        128
+    }
+}
+
+impl Sample for i32 {
+    const FORMAT: SampleFormat = SampleFormat::I32;
+
+    #[inline]
+    fn lerp(first: i32, second: i32, numerator: u32, denominator: u32) -> i32 {
+        let d = first as i128
+            + (second as i128 - first as i128) * numerator as i128 / denominator as i128;
+        i32::try_from(d).expect("numerator / denominator is within [0, 1] range")
+    }
+
+    #[inline]
+    fn amplify(self, value: f32) -> i32 {
+        ((self as f32) * value) as i32
+    }
+
+    #[inline]
+    fn to_f32(self) -> f32 {
+        // Convert i32 to f32 in the range [-1.0, 1.0]
+        self as f32 / 2_147_483_648.0
+    }
+
+    #[inline]
+    fn to_i16(self) -> i16 {
+        (self >> 16) as i16
+    }
+
+    #[inline]
+    fn to_u16(self) -> u16 {
+        ((self >> 16) + 32768) as u16
+    }
+
+    #[inline]
+    fn from_sample<S: Sample>(s: S) -> i32 {
+        (s.to_i16() as i32) << 16
+    }
+
+    #[inline]
+    fn quantization_step() -> Option<f32> {
+        Some(1.0 / 2_147_483_648.0)
+    }
+
+    #[inline]
+    fn from_f32_quantized(value: f32) -> i32 {
+        round_f64(value as f64 * 2_147_483_648.0).clamp(i32::MIN as f64, i32::MAX as f64) as i32
+    }
+
+    #[inline]
+    fn saturating_add(self, other: i32) -> i32 {
+        self.saturating_add(other)
+    }
+
+    #[inline]
+    fn zero_value() -> i32 {
+        0
+    }
+}
+
+impl Sample for I24 {
+    const FORMAT: SampleFormat = SampleFormat::I24;
+
+    #[inline]
+    fn lerp(first: I24, second: I24, numerator: u32, denominator: u32) -> I24 {
+        let d = first.0 as i64
+            + (second.0 as i64 - first.0 as i64) * numerator as i64 / denominator as i64;
+        I24::new(d as i32)
+    }
+
+    #[inline]
+    fn amplify(self, value: f32) -> I24 {
+        I24::new(((self.0 as f32) * value) as i32)
+    }
+
+    #[inline]
+    fn to_f32(self) -> f32 {
+        // Convert I24 to f32 in the range [-1.0, 1.0]
+        self.0 as f32 / 8_388_608.0
+    }
+
+    #[inline]
+    fn to_i16(self) -> i16 {
+        (self.0 >> 8) as i16
+    }
+
+    #[inline]
+    fn to_u16(self) -> u16 {
+        ((self.0 >> 8) + 32768) as u16
+    }
+
+    #[inline]
+    fn from_sample<S: Sample>(s: S) -> I24 {
+        I24::new((s.to_i16() as i32) << 8)
+    }
+
+    #[inline]
+    fn quantization_step() -> Option<f32> {
+        Some(1.0 / 8_388_608.0)
+    }
+
+    #[inline]
+    fn from_f32_quantized(value: f32) -> I24 {
+        I24::new(round_f32(value * 8_388_608.0) as i32)
+    }
+
+    #[inline]
+    fn saturating_add(self, other: I24) -> I24 {
+        I24::new(self.0.saturating_add(other.0))
+    }
+
+    #[inline]
+    fn zero_value() -> I24 {
+        I24(0)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -236,6 +977,82 @@ mod test {
         Sample::lerp(0i16, -1, (i16::MIN.abs() + 1) as u32, 1);
     }
 
+    #[test]
+    fn lerp_i32_constraints() {
+        let a = 12i32;
+        let b = 31i32;
+        assert_eq!(Sample::lerp(a, b, 0, 1), a);
+        assert_eq!(Sample::lerp(a, b, 1, 1), b);
+
+        assert_eq!(Sample::lerp(0, i32::MAX, 0, 1), 0);
+        assert_eq!(Sample::lerp(0, i32::MAX, 1, 1), i32::MAX);
+        assert_eq!(Sample::lerp(0, i32::MIN, 1, 1), i32::MIN);
+    }
+
+    #[test]
+    fn i24_saturating_add_clamps() {
+        assert_eq!(
+            Sample::saturating_add(I24::new(I24::MAX), I24::new(1)),
+            I24::new(I24::MAX)
+        );
+        assert_eq!(
+            Sample::saturating_add(I24::new(I24::MIN), I24::new(-1)),
+            I24::new(I24::MIN)
+        );
+    }
+
+    #[test]
+    fn integer_domain_u16_to_i16_is_lossless() {
+        let input = [0u16, 32768, 65535];
+        let out: Vec<i16> = DataConverter::new_integer(input.iter().copied()).collect();
+        assert_eq!(out, [i16::MIN, 0, 32767]);
+    }
+
+    #[test]
+    fn sample_format_sizes_and_const() {
+        assert_eq!(SampleFormat::I24.sample_size(), 3);
+        assert_eq!(SampleFormat::F64.sample_size(), 8);
+        assert_eq!(<i16 as Sample>::FORMAT, SampleFormat::I16);
+        assert_eq!(<I24 as Sample>::FORMAT, SampleFormat::I24);
+    }
+
+    #[test]
+    fn any_data_converter_emits_le_bytes() {
+        let input = [0i16, i16::MAX, i16::MIN];
+        let bytes: Vec<u8> = AnyDataConverter::new(input.iter().copied(), SampleFormat::I16).collect();
+        assert_eq!(bytes.len(), input.len() * SampleFormat::I16.sample_size());
+        assert_eq!(&bytes[0..2], &0i16.to_le_bytes());
+        assert_eq!(&bytes[2..4], &i16::MAX.to_le_bytes());
+    }
+
+    #[test]
+    fn dither_stays_within_one_lsb() {
+        // For a constant input the dithered output must never stray more than one LSB from the
+        // plain quantization.
+        let value = 0.123_45f32;
+        // Compare against the same quantizer the dither path uses (round, not cpal's truncation).
+        let plain = round_f32(value * 32768.0) as i32;
+        let input = std::iter::repeat(value).take(1000);
+        for out in DataConverter::<_, i16>::new_with_dither(input) {
+            assert!((out as i32 - plain).abs() <= 1);
+        }
+    }
+
+    #[test]
+    fn dither_is_noop_on_upward_conversion() {
+        // i8 -> i16 adds resolution, so dithering must not alter the result.
+        let input = [-128i8, 0, 42, 127];
+        let plain: Vec<i16> = DataConverter::new(input.iter().copied()).collect();
+        let dithered: Vec<i16> = DataConverter::new_with_dither(input.iter().copied()).collect();
+        assert_eq!(plain, dithered);
+    }
+
+    #[test]
+    fn i24_to_f32_bounds() {
+        assert_eq!(I24::new(I24::MIN).to_f32(), -1.0);
+        assert_eq!(I24::new(0).to_f32(), 0.0);
+    }
+
     quickcheck! {
         fn lerp_u16_random(first: u16, second: u16, numerator: u32, denominator: u32) -> TestResult {
             if denominator == 0 { return TestResult::discard(); }